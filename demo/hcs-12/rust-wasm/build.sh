@@ -1,9 +1,21 @@
 #!/bin/bash
 
-# Build the WASM module
-wasm-pack build --target web --out-dir pkg
+if [ "$1" = "--wasi" ]; then
+    # Build the WASI preview 2 component for wasmtime-based hosts
+    cargo build --release --target wasm32-wasip2 --no-default-features --features wasi
+
+    echo "Build complete! Component available at target/wasm32-wasip2/release/hashlink_counter.wasm"
+    exit 0
+fi
+
+# Build the WASM module; --tiny swaps serde_json for the hand-rolled JSON path
+if [ "$1" = "--tiny" ]; then
+    wasm-pack build --target web --out-dir pkg -- --features tiny
+else
+    wasm-pack build --target web --out-dir pkg
+fi
 
 # Optimize the WASM file
 wasm-opt pkg/hashlink_counter_bg.wasm -Oz -o pkg/hashlink_counter_bg_optimized.wasm
 
-echo "Build complete! WASM module available at pkg/"
\ No newline at end of file
+echo "Build complete! WASM module available at pkg/"
@@ -0,0 +1,271 @@
+#[cfg(not(feature = "tiny"))]
+use serde_json::json;
+
+#[cfg(not(feature = "tiny"))]
+use crate::info::module_info;
+
+#[cfg(feature = "tiny")]
+pub use crate::tiny::{get, info, post};
+
+#[cfg(not(feature = "tiny"))]
+pub fn info() -> Result<String, String> {
+    serde_json::to_string(&module_info())
+        .map_err(|e| format!("Failed to serialize info: {}", e))
+}
+
+#[cfg(not(feature = "tiny"))]
+pub fn post(
+    action: &str,
+    params: &str,
+    network: &str,
+    hash_link_memo: &str,
+) -> Result<String, String> {
+    log::debug!(action = action, params = params, network = network, hash_link_memo = hash_link_memo; "POST");
+
+    let params_json: serde_json::Value = serde_json::from_str(params)
+        .map_err(|e| format!("Failed to parse params: {}", e))?;
+
+    match action {
+        "increment" => {
+            let amount = params_json.get("amount")
+                .and_then(|v| v.as_f64())
+                .unwrap_or(1.0) as i32;
+
+            let count = params_json.get("count")
+                .and_then(|v| v.as_f64())
+                .ok_or_else(|| String::from("Missing required parameter: count"))? as i32;
+
+            let new_count = count
+                .checked_add(amount)
+                .ok_or_else(|| String::from("Counter value out of range"))?;
+
+            Ok(json!({
+                "success": true,
+                "data": {
+                    "count": new_count
+                },
+                "message": format!("Counter incremented by {} to {}", amount, new_count)
+            }).to_string())
+        }
+        "decrement" => {
+            let amount = params_json.get("amount")
+                .and_then(|v| v.as_f64())
+                .unwrap_or(1.0) as i32;
+
+            let count = params_json.get("count")
+                .and_then(|v| v.as_f64())
+                .ok_or_else(|| String::from("Missing required parameter: count"))? as i32;
+
+            let new_count = count
+                .checked_sub(amount)
+                .ok_or_else(|| String::from("Counter value out of range"))?;
+
+            Ok(json!({
+                "success": true,
+                "data": {
+                    "count": new_count
+                },
+                "message": format!("Counter decremented by {} to {}", amount, new_count)
+            }).to_string())
+        }
+        "reset" => {
+            Ok(json!({
+                "success": true,
+                "data": {
+                    "count": 0
+                },
+                "message": "Counter reset to 0"
+            }).to_string())
+        }
+        "toggleCounter" => {
+            let show_counter = params_json.get("showCounter")
+                .and_then(|v| v.as_bool())
+                .ok_or_else(|| String::from("Missing required parameter: showCounter"))?;
+
+            let new_state = !show_counter;
+
+            Ok(json!({
+                "success": true,
+                "data": {
+                    "showCounter": new_state
+                },
+                "message": format!("Counter visibility toggled to {}", new_state)
+            }).to_string())
+        }
+        "toggleStats" => {
+            let show_stats = params_json.get("showStats")
+                .and_then(|v| v.as_bool())
+                .ok_or_else(|| String::from("Missing required parameter: showStats"))?;
+
+            let new_state = !show_stats;
+
+            Ok(json!({
+                "success": true,
+                "data": {
+                    "showStats": new_state
+                },
+                "message": format!("Stats visibility toggled to {}", new_state)
+            }).to_string())
+        }
+        _ => {
+            log::warn!(action = action; "POST called with unknown action");
+            Ok(json!({
+                "success": false,
+                "error": format!("Unknown action: {}", action)
+            }).to_string())
+        }
+    }
+}
+
+#[cfg(not(feature = "tiny"))]
+pub fn get(action: &str, params: &str, network: &str) -> Result<String, String> {
+    log::debug!(action = action, params = params, network = network; "GET");
+
+    match action {
+        "increment" => {
+            Ok(json!({
+                "title": "Increment Counter",
+                "description": "Increase the counter value",
+                "label": "Increment",
+                "parameters": [
+                    {
+                        "type": "number",
+                        "name": "amount",
+                        "label": "Amount to increment",
+                        "required": false,
+                        "default": 1,
+                        "min": 1,
+                        "max": 100
+                    }
+                ]
+            }).to_string())
+        }
+        "decrement" => {
+            Ok(json!({
+                "title": "Decrement Counter",
+                "description": "Decrease the counter value",
+                "label": "Decrement",
+                "parameters": [
+                    {
+                        "type": "number",
+                        "name": "amount",
+                        "label": "Amount to decrement",
+                        "required": false,
+                        "default": 1,
+                        "min": 1,
+                        "max": 100
+                    }
+                ]
+            }).to_string())
+        }
+        "reset" => {
+            Ok(json!({
+                "title": "Reset Counter",
+                "description": "Reset the counter to zero",
+                "label": "Reset",
+                "parameters": []
+            }).to_string())
+        }
+        "toggleCounter" => {
+            Ok(json!({
+                "title": "Toggle Counter",
+                "description": "Toggle visibility of the counter block",
+                "label": "Toggle Counter",
+                "parameters": []
+            }).to_string())
+        }
+        "toggleStats" => {
+            Ok(json!({
+                "title": "Toggle Stats",
+                "description": "Toggle visibility of the stats block",
+                "label": "Toggle Stats",
+                "parameters": []
+            }).to_string())
+        }
+        _ => {
+            log::warn!(action = action; "GET called with unknown action");
+            Ok(json!({
+                "error": format!("Unknown action: {}", action)
+            }).to_string())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::Value;
+
+    fn parse(output: &str) -> Value {
+        serde_json::from_str(output).expect("action output is valid JSON")
+    }
+
+    #[test]
+    fn increment_adds_amount_to_count() {
+        let out = parse(&post("increment", r#"{"count": 5, "amount": 3}"#, "testnet", "").unwrap());
+        assert_eq!(out["success"], true);
+        assert_eq!(out["data"]["count"], 8);
+        assert_eq!(out["message"], "Counter incremented by 3 to 8");
+    }
+
+    #[test]
+    fn increment_defaults_amount_to_one() {
+        let out = parse(&post("increment", r#"{"count": 5}"#, "testnet", "").unwrap());
+        assert_eq!(out["data"]["count"], 6);
+    }
+
+    #[test]
+    fn decrement_subtracts_amount_from_count() {
+        let out = parse(&post("decrement", r#"{"count": 5, "amount": 2}"#, "testnet", "").unwrap());
+        assert_eq!(out["success"], true);
+        assert_eq!(out["data"]["count"], 3);
+        assert_eq!(out["message"], "Counter decremented by 2 to 3");
+    }
+
+    #[test]
+    fn missing_count_is_an_error() {
+        for action in ["increment", "decrement"] {
+            let err = post(action, r#"{"amount": 1}"#, "testnet", "").unwrap_err();
+            assert_eq!(err, "Missing required parameter: count");
+        }
+    }
+
+    #[test]
+    fn invalid_params_are_an_error() {
+        let err = post("increment", "not json", "testnet", "").unwrap_err();
+        assert!(err.starts_with("Failed to parse params:"), "{}", err);
+    }
+
+    #[test]
+    fn unknown_post_action_reports_failure() {
+        let out = parse(&post("explode", "{}", "testnet", "").unwrap());
+        assert_eq!(out["success"], false);
+        assert_eq!(out["error"], "Unknown action: explode");
+    }
+
+    #[test]
+    fn unknown_get_action_reports_error() {
+        let out = parse(&get("explode", "{}", "testnet").unwrap());
+        assert_eq!(out["error"], "Unknown action: explode");
+    }
+
+    #[test]
+    fn info_round_trips() {
+        let json = info().unwrap();
+        let decoded: crate::info::ModuleInfo = serde_json::from_str(&json).unwrap();
+        assert_eq!(serde_json::to_string(&decoded).unwrap(), json);
+
+        let value = parse(&json);
+        assert_eq!(value["name"], "Demo Actions Module");
+        let actions: Vec<&str> = value["actions"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|a| a["name"].as_str().unwrap())
+            .collect();
+        assert_eq!(
+            actions,
+            ["increment", "decrement", "reset", "toggleCounter", "toggleStats"]
+        );
+    }
+}
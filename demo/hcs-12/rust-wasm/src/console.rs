@@ -0,0 +1,69 @@
+use log::{Level, LevelFilter, Log, Metadata, Record};
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = console, js_name = error)]
+    fn console_error(message: &str);
+    #[wasm_bindgen(js_namespace = console, js_name = warn)]
+    fn console_warn(message: &str);
+    #[wasm_bindgen(js_namespace = console, js_name = info)]
+    fn console_info(message: &str);
+    #[wasm_bindgen(js_namespace = console, js_name = debug)]
+    fn console_debug(message: &str);
+}
+
+/// Forwards `log` records to the matching `console` method, appending any
+/// structured key-values as `key=value` pairs.
+struct ConsoleLogger;
+
+struct KeyValueWriter<'a>(&'a mut String);
+
+impl<'kvs> log::kv::VisitSource<'kvs> for KeyValueWriter<'_> {
+    fn visit_pair(
+        &mut self,
+        key: log::kv::Key<'kvs>,
+        value: log::kv::Value<'kvs>,
+    ) -> Result<(), log::kv::Error> {
+        use std::fmt::Write;
+        write!(self.0, " {}={}", key, value).map_err(|_| log::kv::Error::msg("format failed"))
+    }
+}
+
+impl Log for ConsoleLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= log::max_level()
+    }
+
+    fn log(&self, record: &Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+
+        let mut line = format!("[{} {}] {}", record.level(), record.target(), record.args());
+        let _ = record.key_values().visit(&mut KeyValueWriter(&mut line));
+
+        match record.level() {
+            Level::Error => console_error(&line),
+            Level::Warn => console_warn(&line),
+            Level::Info => console_info(&line),
+            Level::Debug | Level::Trace => console_debug(&line),
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+static LOGGER: ConsoleLogger = ConsoleLogger;
+
+/// Installs the console logger at the given level (`off`, `error`, `warn`,
+/// `info`, `debug` or `trace`). Logging stays off until this is called.
+#[wasm_bindgen(js_name = initLogging)]
+pub fn init_logging(level: &str) -> Result<(), JsValue> {
+    let filter: LevelFilter = level
+        .parse()
+        .map_err(|_| JsValue::from_str(&format!("Unknown log level: {}", level)))?;
+    let _ = log::set_logger(&LOGGER);
+    log::set_max_level(filter);
+    Ok(())
+}
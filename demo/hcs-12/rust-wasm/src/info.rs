@@ -0,0 +1,339 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize)]
+pub struct ModuleInfo {
+    name: String,
+    version: String,
+    hashlinks_version: String,
+    creator: String,
+    purpose: String,
+    actions: Vec<ActionDefinition>,
+    capabilities: Vec<Capability>,
+    plugins: Vec<PluginDefinition>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ActionDefinition {
+    name: String,
+    description: String,
+    inputs: Vec<ParameterDefinition>,
+    outputs: Vec<ParameterDefinition>,
+    required_capabilities: Vec<Capability>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ParameterDefinition {
+    name: String,
+    param_type: String,
+    description: String,
+    required: bool,
+    validation: Option<ValidationRule>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ValidationRule {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    min: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max: Option<f64>,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(tag = "type")]
+pub enum Capability {
+    #[serde(rename = "network")]
+    Network { value: NetworkCapability },
+    #[serde(rename = "transaction")]
+    Transaction { value: TransactionCapability },
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct NetworkCapability {
+    networks: Vec<String>,
+    operations: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct TransactionCapability {
+    transaction_types: Vec<String>,
+    max_fee_hbar: Option<f64>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct PluginDefinition {
+    name: String,
+    version: String,
+    url: String,
+    description: String,
+    required: bool,
+}
+
+pub fn module_info() -> ModuleInfo {
+    ModuleInfo {
+        name: "Demo Actions Module".to_string(),
+        version: "1.0.0".to_string(),
+        hashlinks_version: "0.1.0".to_string(),
+        creator: "HashGraph Online".to_string(),
+        purpose: "Demo actions for counter and container blocks".to_string(),
+        actions: vec![
+            ActionDefinition {
+                name: "increment".to_string(),
+                description: "Increment the counter".to_string(),
+                inputs: vec![
+                    ParameterDefinition {
+                        name: "amount".to_string(),
+                        param_type: "number".to_string(),
+                        description: "Amount to increment by".to_string(),
+                        required: false,
+                        validation: Some(ValidationRule {
+                            min: Some(1.0),
+                            max: Some(100.0),
+                        }),
+                    },
+                    ParameterDefinition {
+                        name: "count".to_string(),
+                        param_type: "number".to_string(),
+                        description: "Current counter value".to_string(),
+                        required: true,
+                        validation: None,
+                    },
+                ],
+                outputs: vec![
+                    ParameterDefinition {
+                        name: "count".to_string(),
+                        param_type: "number".to_string(),
+                        description: "Updated counter value".to_string(),
+                        required: true,
+                        validation: None,
+                    },
+                ],
+                required_capabilities: vec![],
+            },
+            ActionDefinition {
+                name: "decrement".to_string(),
+                description: "Decrement the counter".to_string(),
+                inputs: vec![
+                    ParameterDefinition {
+                        name: "amount".to_string(),
+                        param_type: "number".to_string(),
+                        description: "Amount to decrement by".to_string(),
+                        required: false,
+                        validation: Some(ValidationRule {
+                            min: Some(1.0),
+                            max: Some(100.0),
+                        }),
+                    },
+                    ParameterDefinition {
+                        name: "count".to_string(),
+                        param_type: "number".to_string(),
+                        description: "Current counter value".to_string(),
+                        required: true,
+                        validation: None,
+                    },
+                ],
+                outputs: vec![
+                    ParameterDefinition {
+                        name: "count".to_string(),
+                        param_type: "number".to_string(),
+                        description: "Updated counter value".to_string(),
+                        required: true,
+                        validation: None,
+                    },
+                ],
+                required_capabilities: vec![],
+            },
+            ActionDefinition {
+                name: "reset".to_string(),
+                description: "Reset the counter to zero".to_string(),
+                inputs: vec![],
+                outputs: vec![
+                    ParameterDefinition {
+                        name: "count".to_string(),
+                        param_type: "number".to_string(),
+                        description: "Reset counter value (0)".to_string(),
+                        required: true,
+                        validation: None,
+                    },
+                ],
+                required_capabilities: vec![],
+            },
+            ActionDefinition {
+                name: "toggleCounter".to_string(),
+                description: "Toggle visibility of counter block".to_string(),
+                inputs: vec![
+                    ParameterDefinition {
+                        name: "showCounter".to_string(),
+                        param_type: "boolean".to_string(),
+                        description: "Current visibility state of counter".to_string(),
+                        required: true,
+                        validation: None,
+                    },
+                ],
+                outputs: vec![
+                    ParameterDefinition {
+                        name: "showCounter".to_string(),
+                        param_type: "boolean".to_string(),
+                        description: "Updated visibility state".to_string(),
+                        required: true,
+                        validation: None,
+                    },
+                ],
+                required_capabilities: vec![],
+            },
+            ActionDefinition {
+                name: "toggleStats".to_string(),
+                description: "Toggle visibility of stats block".to_string(),
+                inputs: vec![
+                    ParameterDefinition {
+                        name: "showStats".to_string(),
+                        param_type: "boolean".to_string(),
+                        description: "Current visibility state of stats".to_string(),
+                        required: true,
+                        validation: None,
+                    },
+                ],
+                outputs: vec![
+                    ParameterDefinition {
+                        name: "showStats".to_string(),
+                        param_type: "boolean".to_string(),
+                        description: "Updated visibility state".to_string(),
+                        required: true,
+                        validation: None,
+                    },
+                ],
+                required_capabilities: vec![],
+            },
+        ],
+        capabilities: vec![
+            Capability::Network {
+                value: NetworkCapability {
+                    networks: vec!["mainnet".to_string(), "testnet".to_string()],
+                    operations: vec!["query".to_string()],
+                },
+            },
+        ],
+        plugins: vec![],
+    }
+}
+
+#[cfg(any(feature = "tiny", test))]
+mod tiny {
+    use super::*;
+    use crate::json::{Array, Object};
+
+    impl ModuleInfo {
+        /// Writes the same JSON as serde_json does for this struct.
+        pub(crate) fn write_json(&self, out: &mut String) {
+            Object::write(out, |o| {
+                o.str("name", &self.name)
+                    .str("version", &self.version)
+                    .str("hashlinks_version", &self.hashlinks_version)
+                    .str("creator", &self.creator)
+                    .str("purpose", &self.purpose)
+                    .array("actions", |a| {
+                        for action in &self.actions {
+                            a.object(|o| action.write_fields(o));
+                        }
+                    })
+                    .array("capabilities", |a| write_capabilities(a, &self.capabilities))
+                    .array("plugins", |a| {
+                        for plugin in &self.plugins {
+                            a.object(|o| plugin.write_fields(o));
+                        }
+                    });
+            });
+        }
+    }
+
+    fn write_capabilities(a: &mut Array, capabilities: &[Capability]) {
+        for capability in capabilities {
+            a.object(|o| capability.write_fields(o));
+        }
+    }
+
+    fn write_parameters(a: &mut Array, parameters: &[ParameterDefinition]) {
+        for parameter in parameters {
+            a.object(|o| parameter.write_fields(o));
+        }
+    }
+
+    impl ActionDefinition {
+        fn write_fields(&self, o: &mut Object) {
+            o.str("name", &self.name)
+                .str("description", &self.description)
+                .array("inputs", |a| write_parameters(a, &self.inputs))
+                .array("outputs", |a| write_parameters(a, &self.outputs))
+                .array("required_capabilities", |a| {
+                    write_capabilities(a, &self.required_capabilities)
+                });
+        }
+    }
+
+    impl ParameterDefinition {
+        fn write_fields(&self, o: &mut Object) {
+            o.str("name", &self.name)
+                .str("param_type", &self.param_type)
+                .str("description", &self.description)
+                .bool("required", self.required);
+            match &self.validation {
+                Some(rule) => o.object("validation", |o| rule.write_fields(o)),
+                None => o.null("validation"),
+            };
+        }
+    }
+
+    impl ValidationRule {
+        fn write_fields(&self, o: &mut Object) {
+            if let Some(min) = self.min {
+                o.float("min", min);
+            }
+            if let Some(max) = self.max {
+                o.float("max", max);
+            }
+        }
+    }
+
+    impl Capability {
+        fn write_fields(&self, o: &mut Object) {
+            match self {
+                Capability::Network { value } => {
+                    o.str("type", "network").object("value", |o| {
+                        o.array("networks", |a| {
+                            for network in &value.networks {
+                                a.str(network);
+                            }
+                        })
+                        .array("operations", |a| {
+                            for operation in &value.operations {
+                                a.str(operation);
+                            }
+                        });
+                    });
+                }
+                Capability::Transaction { value } => {
+                    o.str("type", "transaction").object("value", |o| {
+                        o.array("transaction_types", |a| {
+                            for transaction_type in &value.transaction_types {
+                                a.str(transaction_type);
+                            }
+                        });
+                        match value.max_fee_hbar {
+                            Some(fee) => o.float("max_fee_hbar", fee),
+                            None => o.null("max_fee_hbar"),
+                        };
+                    });
+                }
+            }
+        }
+    }
+
+    impl PluginDefinition {
+        fn write_fields(&self, o: &mut Object) {
+            o.str("name", &self.name)
+                .str("version", &self.version)
+                .str("url", &self.url)
+                .str("description", &self.description)
+                .bool("required", self.required);
+        }
+    }
+}
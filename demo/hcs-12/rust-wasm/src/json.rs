@@ -0,0 +1,553 @@
+//! Hand-rolled JSON used by the `tiny` build in place of serde_json. It only
+//! covers what the counter actions need: writing objects and arrays, and
+//! reading scalar fields from the top level of the params object. Nothing
+//! here goes through `core::fmt`.
+
+/// Top-level field value as seen by an action. Strings, nulls, arrays and
+/// objects are validated but not kept.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Scalar {
+    Number(f64),
+    Bool(bool),
+    Other,
+}
+
+/// Parsed params document. Only top-level object fields are kept; any other
+/// valid JSON value has no fields, like `serde_json::Value::get`.
+pub struct Fields {
+    fields: Vec<(String, Scalar)>,
+}
+
+impl Fields {
+    /// Returns the value of `key`. When a key repeats, the last one wins.
+    pub fn get(&self, key: &str) -> Option<Scalar> {
+        self.fields
+            .iter()
+            .rev()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| *v)
+    }
+
+    pub fn number(&self, key: &str) -> Option<f64> {
+        match self.get(key) {
+            Some(Scalar::Number(n)) => Some(n),
+            _ => None,
+        }
+    }
+
+    pub fn bool(&self, key: &str) -> Option<bool> {
+        match self.get(key) {
+            Some(Scalar::Bool(b)) => Some(b),
+            _ => None,
+        }
+    }
+}
+
+/// Parses `input` as a single JSON value.
+pub fn parse(input: &str) -> Result<Fields, ()> {
+    let mut parser = Parser {
+        bytes: input.as_bytes(),
+        pos: 0,
+        remaining_depth: MAX_DEPTH,
+    };
+    parser.skip_ws();
+    let fields = if parser.peek() == Some(b'{') {
+        parser.nested(|p| p.object(true))?
+    } else {
+        parser.value()?;
+        Vec::new()
+    };
+    parser.skip_ws();
+    if parser.pos != parser.bytes.len() {
+        return Err(());
+    }
+    Ok(Fields { fields })
+}
+
+/// Nesting limit for arrays and objects, the same as serde_json's. Deeper
+/// documents are rejected instead of overflowing the stack.
+const MAX_DEPTH: u8 = 128;
+
+struct Parser<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+    remaining_depth: u8,
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.pos).copied()
+    }
+
+    fn next(&mut self) -> Result<u8, ()> {
+        let b = self.peek().ok_or(())?;
+        self.pos += 1;
+        Ok(b)
+    }
+
+    fn expect(&mut self, b: u8) -> Result<(), ()> {
+        if self.next()? == b {
+            Ok(())
+        } else {
+            Err(())
+        }
+    }
+
+    /// Runs `f` one nesting level deeper, failing once the limit is reached.
+    fn nested<T>(&mut self, f: impl FnOnce(&mut Self) -> Result<T, ()>) -> Result<T, ()> {
+        self.remaining_depth -= 1;
+        if self.remaining_depth == 0 {
+            return Err(());
+        }
+        let result = f(self);
+        self.remaining_depth += 1;
+        result
+    }
+
+    fn skip_ws(&mut self) {
+        while matches!(self.peek(), Some(b' ' | b'\t' | b'\n' | b'\r')) {
+            self.pos += 1;
+        }
+    }
+
+    fn value(&mut self) -> Result<Scalar, ()> {
+        self.skip_ws();
+        match self.peek().ok_or(())? {
+            b'{' => self.nested(|p| p.object(false)).map(|_| Scalar::Other),
+            b'[' => self.nested(Self::array).map(|_| Scalar::Other),
+            b'"' => self.string().map(|_| Scalar::Other),
+            b't' => self.literal(b"true").map(|_| Scalar::Bool(true)),
+            b'f' => self.literal(b"false").map(|_| Scalar::Bool(false)),
+            b'n' => self.literal(b"null").map(|_| Scalar::Other),
+            _ => self.number().map(Scalar::Number),
+        }
+    }
+
+    fn literal(&mut self, word: &[u8]) -> Result<(), ()> {
+        let end = self.pos + word.len();
+        if self.bytes.get(self.pos..end) == Some(word) {
+            self.pos = end;
+            Ok(())
+        } else {
+            Err(())
+        }
+    }
+
+    /// Parses an object. Fields are only collected when `keep` is set, so
+    /// nested objects do not allocate.
+    fn object(&mut self, keep: bool) -> Result<Vec<(String, Scalar)>, ()> {
+        let mut fields = Vec::new();
+        self.expect(b'{')?;
+        self.skip_ws();
+        if self.peek() == Some(b'}') {
+            self.pos += 1;
+            return Ok(fields);
+        }
+        loop {
+            self.skip_ws();
+            let key = self.string()?;
+            self.skip_ws();
+            self.expect(b':')?;
+            let value = self.value()?;
+            if keep {
+                fields.push((key, value));
+            }
+            self.skip_ws();
+            match self.next()? {
+                b',' => continue,
+                b'}' => return Ok(fields),
+                _ => return Err(()),
+            }
+        }
+    }
+
+    fn array(&mut self) -> Result<(), ()> {
+        self.expect(b'[')?;
+        self.skip_ws();
+        if self.peek() == Some(b']') {
+            self.pos += 1;
+            return Ok(());
+        }
+        loop {
+            self.value()?;
+            self.skip_ws();
+            match self.next()? {
+                b',' => continue,
+                b']' => return Ok(()),
+                _ => return Err(()),
+            }
+        }
+    }
+
+    fn string(&mut self) -> Result<String, ()> {
+        self.expect(b'"')?;
+        let mut out = Vec::new();
+        loop {
+            match self.next()? {
+                b'"' => return String::from_utf8(out).map_err(|_| ()),
+                b'\\' => {
+                    let c = match self.next()? {
+                        b'"' => '"',
+                        b'\\' => '\\',
+                        b'/' => '/',
+                        b'b' => '\u{8}',
+                        b'f' => '\u{c}',
+                        b'n' => '\n',
+                        b'r' => '\r',
+                        b't' => '\t',
+                        b'u' => self.unicode_escape()?,
+                        _ => return Err(()),
+                    };
+                    let mut buf = [0; 4];
+                    out.extend_from_slice(c.encode_utf8(&mut buf).as_bytes());
+                }
+                b if b < 0x20 => return Err(()),
+                b => out.push(b),
+            }
+        }
+    }
+
+    fn unicode_escape(&mut self) -> Result<char, ()> {
+        let high = self.hex4()?;
+        let code = if (0xD800..0xDC00).contains(&high) {
+            self.literal(b"\\u")?;
+            let low = self.hex4()?;
+            if !(0xDC00..0xE000).contains(&low) {
+                return Err(());
+            }
+            0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00)
+        } else {
+            high
+        };
+        char::from_u32(code).ok_or(())
+    }
+
+    fn hex4(&mut self) -> Result<u32, ()> {
+        let mut n = 0;
+        for _ in 0..4 {
+            let digit = match self.next()? {
+                b @ b'0'..=b'9' => b - b'0',
+                b @ b'a'..=b'f' => b - b'a' + 10,
+                b @ b'A'..=b'F' => b - b'A' + 10,
+                _ => return Err(()),
+            };
+            n = n * 16 + u32::from(digit);
+        }
+        Ok(n)
+    }
+
+    /// Parses a JSON number. The grammar is checked here and the text is
+    /// handed to `f64`'s `FromStr`, which rounds the same way serde_json
+    /// does. Numbers that overflow to infinity are rejected, as serde_json
+    /// rejects them.
+    fn number(&mut self) -> Result<f64, ()> {
+        let start = self.pos;
+        if self.peek() == Some(b'-') {
+            self.pos += 1;
+        }
+
+        match self.next()? {
+            b'0' => {}
+            b'1'..=b'9' => {
+                self.digits();
+            }
+            _ => return Err(()),
+        }
+
+        if self.peek() == Some(b'.') {
+            self.pos += 1;
+            if self.digits() == 0 {
+                return Err(());
+            }
+        }
+
+        if matches!(self.peek(), Some(b'e' | b'E')) {
+            self.pos += 1;
+            if matches!(self.peek(), Some(b'-' | b'+')) {
+                self.pos += 1;
+            }
+            if self.digits() == 0 {
+                return Err(());
+            }
+        }
+
+        let text = core::str::from_utf8(&self.bytes[start..self.pos]).map_err(|_| ())?;
+        let value: f64 = text.parse().map_err(|_| ())?;
+        if value.is_finite() {
+            Ok(value)
+        } else {
+            Err(())
+        }
+    }
+
+    /// Skips a run of ASCII digits and returns how many there were.
+    fn digits(&mut self) -> usize {
+        let start = self.pos;
+        while matches!(self.peek(), Some(b'0'..=b'9')) {
+            self.pos += 1;
+        }
+        self.pos - start
+    }
+}
+
+/// Writes `s` as a JSON string, escaping the same characters serde_json does.
+pub fn write_str(out: &mut String, s: &str) {
+    const HEX: &[u8; 16] = b"0123456789abcdef";
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            '\u{8}' => out.push_str("\\b"),
+            '\u{c}' => out.push_str("\\f"),
+            c if (c as u32) < 0x20 => {
+                out.push_str("\\u00");
+                out.push(HEX[(c as usize) >> 4] as char);
+                out.push(HEX[(c as usize) & 0xf] as char);
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+pub fn write_i64(out: &mut String, n: i64) {
+    if n < 0 {
+        out.push('-');
+    }
+    let mut n = n.unsigned_abs();
+    let mut buf = [0u8; 20];
+    let mut i = buf.len();
+    loop {
+        i -= 1;
+        buf[i] = b'0' + (n % 10) as u8;
+        n /= 10;
+        if n == 0 {
+            break;
+        }
+    }
+    for &b in &buf[i..] {
+        out.push(b as char);
+    }
+}
+
+/// Writes `n` the way serde_json does for whole numbers (`1.0`). Fractions
+/// are written with up to six decimal places, and non-finite values as
+/// `null`.
+pub fn write_f64(out: &mut String, n: f64) {
+    if !n.is_finite() {
+        out.push_str("null");
+        return;
+    }
+    if n.is_sign_negative() && n != 0.0 {
+        out.push('-');
+    }
+    let n = n.abs();
+    let whole = n.trunc();
+    if whole >= 1e15 {
+        // Out of range for the digit loop below; the demo never gets here.
+        out.push_str("null");
+        return;
+    }
+    write_i64(out, whole as i64);
+    out.push('.');
+
+    let mut frac = ((n - whole) * 1e6).round() as i64;
+    if frac == 0 || frac >= 1_000_000 {
+        out.push('0');
+        return;
+    }
+    let mut digits = [b'0'; 6];
+    for d in digits.iter_mut().rev() {
+        *d = b'0' + (frac % 10) as u8;
+        frac /= 10;
+    }
+    let len = digits.iter().rposition(|&d| d != b'0').map_or(1, |i| i + 1);
+    for &d in &digits[..len] {
+        out.push(d as char);
+    }
+}
+
+/// Writes the fields of a JSON object. Keys are written in call order.
+pub struct Object<'a> {
+    out: &'a mut String,
+    empty: bool,
+}
+
+impl Object<'_> {
+    pub fn write(out: &mut String, f: impl FnOnce(&mut Object)) {
+        out.push('{');
+        let mut object = Object { out, empty: true };
+        f(&mut object);
+        object.out.push('}');
+    }
+
+    fn key(&mut self, key: &str) -> &mut String {
+        if !self.empty {
+            self.out.push(',');
+        }
+        self.empty = false;
+        write_str(self.out, key);
+        self.out.push(':');
+        self.out
+    }
+
+    pub fn str(&mut self, key: &str, value: &str) -> &mut Self {
+        write_str(self.key(key), value);
+        self
+    }
+
+    pub fn int(&mut self, key: &str, value: i64) -> &mut Self {
+        write_i64(self.key(key), value);
+        self
+    }
+
+    pub fn float(&mut self, key: &str, value: f64) -> &mut Self {
+        write_f64(self.key(key), value);
+        self
+    }
+
+    pub fn bool(&mut self, key: &str, value: bool) -> &mut Self {
+        self.key(key).push_str(if value { "true" } else { "false" });
+        self
+    }
+
+    pub fn null(&mut self, key: &str) -> &mut Self {
+        self.key(key).push_str("null");
+        self
+    }
+
+    pub fn object(&mut self, key: &str, f: impl FnOnce(&mut Object)) -> &mut Self {
+        Object::write(self.key(key), f);
+        self
+    }
+
+    pub fn array(&mut self, key: &str, f: impl FnOnce(&mut Array)) -> &mut Self {
+        Array::write(self.key(key), f);
+        self
+    }
+}
+
+/// Writes the elements of a JSON array.
+pub struct Array<'a> {
+    out: &'a mut String,
+    empty: bool,
+}
+
+impl Array<'_> {
+    pub fn write(out: &mut String, f: impl FnOnce(&mut Array)) {
+        out.push('[');
+        let mut array = Array { out, empty: true };
+        f(&mut array);
+        array.out.push(']');
+    }
+
+    fn element(&mut self) -> &mut String {
+        if !self.empty {
+            self.out.push(',');
+        }
+        self.empty = false;
+        self.out
+    }
+
+    pub fn str(&mut self, value: &str) -> &mut Self {
+        write_str(self.element(), value);
+        self
+    }
+
+    pub fn object(&mut self, f: impl FnOnce(&mut Object)) -> &mut Self {
+        Object::write(self.element(), f);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_top_level_scalars() {
+        let fields =
+            parse(r#" {"count": -12.5e1, "on": true, "nested": {"count": 1}, "s": "a\"é😀"} "#)
+                .unwrap();
+        assert_eq!(fields.number("count"), Some(-125.0));
+        assert_eq!(fields.bool("on"), Some(true));
+        assert_eq!(fields.get("nested"), Some(Scalar::Other));
+        assert_eq!(fields.get("s"), Some(Scalar::Other));
+        assert_eq!(fields.get("missing"), None);
+    }
+
+    #[test]
+    fn last_duplicate_key_wins() {
+        let fields = parse(r#"{"count": 1, "count": 2}"#).unwrap();
+        assert_eq!(fields.number("count"), Some(2.0));
+    }
+
+    #[test]
+    fn non_object_documents_have_no_fields() {
+        for input in ["[1, 2]", "\"x\"", "null", "3"] {
+            assert_eq!(parse(input).unwrap().get("count"), None);
+        }
+    }
+
+    #[test]
+    fn rejects_invalid_documents() {
+        for input in [
+            "",
+            "{",
+            "{\"a\" 1}",
+            "{\"a\": 1,}",
+            "[1,]",
+            "01",
+            "1.",
+            "1e",
+            "tru",
+            "{} x",
+            "\"\u{1}\"",
+        ] {
+            assert!(parse(input).is_err(), "{:?} should not parse", input);
+        }
+    }
+
+    #[test]
+    fn rejects_nesting_past_the_limit() {
+        let nested = |depth: usize| format!("{}{}", "[".repeat(depth), "]".repeat(depth));
+        assert!(parse(&nested(127)).is_ok());
+        assert!(parse(&nested(128)).is_err());
+        assert!(parse(&"[".repeat(1_000_000)).is_err());
+    }
+
+    #[test]
+    fn writes_like_serde_json() {
+        let mut out = String::new();
+        Object::write(&mut out, |o| {
+            o.str("s", "q\"\\\n\u{1}é")
+                .int("min", i64::from(i32::MIN))
+                .float("f", 100.0)
+                .float("half", -0.5)
+                .null("n")
+                .array("a", |a| {
+                    a.str("x").object(|o| {
+                        o.bool("b", false);
+                    });
+                });
+        });
+        let expected = serde_json::json!({
+            "s": "q\"\\\n\u{1}é",
+            "min": i32::MIN,
+            "f": 100.0,
+            "half": -0.5,
+            "n": null,
+            "a": ["x", { "b": false }],
+        });
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(&out).unwrap(),
+            expected
+        );
+        assert!(out.contains(r#""s":"q\"\\\n\u0001é""#), "{}", out);
+        assert!(out.contains(r#""f":100.0"#), "{}", out);
+    }
+}
@@ -0,0 +1,24 @@
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = console, js_name = error)]
+    fn console_error(message: &str);
+}
+
+/// Runs on instantiation, before any export can be called. Panics abort
+/// on wasm32-unknown-unknown, so the hook can only report the panic through
+/// `console.error` before the instance traps. No `INTERNAL_ERROR` response
+/// is returned: the export that panicked traps and returns nothing.
+///
+/// The `tiny` build reports a fixed message instead of the formatted panic,
+/// so it links no formatting code of its own.
+#[wasm_bindgen(start)]
+fn start() {
+    #[cfg(not(feature = "tiny"))]
+    std::panic::set_hook(Box::new(|panic_info| {
+        console_error(&format!("hashlink-counter panicked: {}", panic_info));
+    }));
+    #[cfg(feature = "tiny")]
+    std::panic::set_hook(Box::new(|_| console_error("hashlink-counter panicked")));
+}
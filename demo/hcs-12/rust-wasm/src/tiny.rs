@@ -0,0 +1,254 @@
+//! The `tiny` build of the counter actions. Same inputs and outputs as the
+//! serde_json path in actions.rs, except that invalid params get a generic
+//! parse error. Written with the hand-rolled JSON in json.rs and without
+//! `format!`, so the module links no serde_json and no `core::fmt` of its
+//! own. Object keys are written in sorted order to match what
+//! `serde_json::json!` produces.
+
+use crate::info::module_info;
+use crate::json::{self, Object};
+
+const PARSE_ERROR: &str = "Failed to parse params: invalid JSON";
+const OUT_OF_RANGE: &str = "Counter value out of range";
+
+pub fn info() -> Result<String, String> {
+    let mut out = String::new();
+    module_info().write_json(&mut out);
+    Ok(out)
+}
+
+fn push_int(out: &mut String, n: i32) {
+    json::write_i64(out, i64::from(n));
+}
+
+fn counter_result(new_count: i32, message: &str) -> String {
+    let mut out = String::new();
+    Object::write(&mut out, |o| {
+        o.object("data", |o| {
+            o.int("count", i64::from(new_count));
+        })
+        .str("message", message)
+        .bool("success", true);
+    });
+    out
+}
+
+fn toggle_result(key: &str, new_state: bool, label: &str) -> String {
+    let mut message = String::from(label);
+    message.push_str(" visibility toggled to ");
+    message.push_str(if new_state { "true" } else { "false" });
+
+    let mut out = String::new();
+    Object::write(&mut out, |o| {
+        o.object("data", |o| {
+            o.bool(key, new_state);
+        })
+        .str("message", &message)
+        .bool("success", true);
+    });
+    out
+}
+
+fn unknown_action(action: &str) -> String {
+    let mut error = String::from("Unknown action: ");
+    error.push_str(action);
+    error
+}
+
+fn amount_and_count(params: &json::Fields) -> Result<(i32, i32), String> {
+    let amount = params.number("amount").unwrap_or(1.0) as i32;
+    let count = params
+        .number("count")
+        .ok_or_else(|| String::from("Missing required parameter: count"))? as i32;
+    Ok((amount, count))
+}
+
+pub fn post(
+    action: &str,
+    params: &str,
+    _network: &str,
+    _hash_link_memo: &str,
+) -> Result<String, String> {
+    let params = json::parse(params).map_err(|_| String::from(PARSE_ERROR))?;
+
+    match action {
+        "increment" | "decrement" => {
+            let (amount, count) = amount_and_count(&params)?;
+            let (new_count, verb) = if action == "increment" {
+                (count.checked_add(amount), "Counter incremented by ")
+            } else {
+                (count.checked_sub(amount), "Counter decremented by ")
+            };
+            let new_count = new_count.ok_or_else(|| String::from(OUT_OF_RANGE))?;
+
+            let mut message = String::from(verb);
+            push_int(&mut message, amount);
+            message.push_str(" to ");
+            push_int(&mut message, new_count);
+            Ok(counter_result(new_count, &message))
+        }
+        "reset" => Ok(counter_result(0, "Counter reset to 0")),
+        "toggleCounter" => {
+            let show_counter = params
+                .bool("showCounter")
+                .ok_or_else(|| String::from("Missing required parameter: showCounter"))?;
+            Ok(toggle_result("showCounter", !show_counter, "Counter"))
+        }
+        "toggleStats" => {
+            let show_stats = params
+                .bool("showStats")
+                .ok_or_else(|| String::from("Missing required parameter: showStats"))?;
+            Ok(toggle_result("showStats", !show_stats, "Stats"))
+        }
+        _ => {
+            let mut out = String::new();
+            Object::write(&mut out, |o| {
+                o.str("error", &unknown_action(action))
+                    .bool("success", false);
+            });
+            Ok(out)
+        }
+    }
+}
+
+fn action_form(title: &str, description: &str, label: &str, amount_label: Option<&str>) -> String {
+    let mut out = String::new();
+    Object::write(&mut out, |o| {
+        o.str("description", description)
+            .str("label", label)
+            .array("parameters", |a| {
+                if let Some(amount_label) = amount_label {
+                    a.object(|o| {
+                        o.int("default", 1)
+                            .str("label", amount_label)
+                            .int("max", 100)
+                            .int("min", 1)
+                            .str("name", "amount")
+                            .bool("required", false)
+                            .str("type", "number");
+                    });
+                }
+            })
+            .str("title", title);
+    });
+    out
+}
+
+pub fn get(action: &str, _params: &str, _network: &str) -> Result<String, String> {
+    let form = match action {
+        "increment" => action_form(
+            "Increment Counter",
+            "Increase the counter value",
+            "Increment",
+            Some("Amount to increment"),
+        ),
+        "decrement" => action_form(
+            "Decrement Counter",
+            "Decrease the counter value",
+            "Decrement",
+            Some("Amount to decrement"),
+        ),
+        "reset" => action_form("Reset Counter", "Reset the counter to zero", "Reset", None),
+        "toggleCounter" => action_form(
+            "Toggle Counter",
+            "Toggle visibility of the counter block",
+            "Toggle Counter",
+            None,
+        ),
+        "toggleStats" => action_form(
+            "Toggle Stats",
+            "Toggle visibility of the stats block",
+            "Toggle Stats",
+            None,
+        ),
+        _ => {
+            let mut out = String::new();
+            Object::write(&mut out, |o| {
+                o.str("error", &unknown_action(action));
+            });
+            out
+        }
+    };
+    Ok(form)
+}
+
+#[cfg(all(test, not(feature = "tiny")))]
+mod tests {
+    use crate::actions;
+
+    const ACTIONS: [&str; 6] = [
+        "increment",
+        "decrement",
+        "reset",
+        "toggleCounter",
+        "toggleStats",
+        "explode",
+    ];
+
+    const PARAMS: [&str; 14] = [
+        r#"{"count": 5, "amount": 3}"#,
+        r#"{"count": -7}"#,
+        r#"{"count": 2.9, "amount": 1e1}"#,
+        r#"{"amount": 2}"#,
+        r#"{"showCounter": true, "showStats": false}"#,
+        r#"{"showCounter": false, "showStats": true, "count": 0}"#,
+        "[]",
+        "{}",
+        "not json",
+        r#"{"count": 2.99999999999999999999, "amount": 0}"#,
+        r#"{"count": 1e400}"#,
+        r#"{"count": 2147483647, "amount": 1}"#,
+        r#"{"count": -2147483648, "amount": 1}"#,
+        r#"{"count": 1e10, "amount": -5}"#,
+    ];
+
+    #[test]
+    fn info_matches_serde_path() {
+        assert_eq!(super::info(), actions::info());
+    }
+
+    #[test]
+    fn get_matches_serde_path() {
+        for action in ACTIONS {
+            assert_eq!(
+                super::get(action, "{}", "testnet"),
+                actions::get(action, "{}", "testnet"),
+                "{}",
+                action
+            );
+        }
+    }
+
+    #[test]
+    fn post_matches_serde_path() {
+        for action in ACTIONS {
+            for params in PARAMS {
+                let tiny = super::post(action, params, "testnet", "");
+                let serde = actions::post(action, params, "testnet", "");
+                match (&tiny, &serde) {
+                    (Err(t), Err(s)) if s.starts_with("Failed to parse params:") => {
+                        assert!(t.starts_with("Failed to parse params:"), "{}", t)
+                    }
+                    _ => assert_eq!(tiny, serde, "{} {}", action, params),
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn nesting_limit_matches_serde_path() {
+        for depth in [127, 128, 1_000_000] {
+            let params = format!(
+                r#"{{"count": 1, "x": {}{}}}"#,
+                "[".repeat(depth),
+                "]".repeat(depth)
+            );
+            let tiny = super::post("increment", &params, "testnet", "");
+            let serde = actions::post("increment", &params, "testnet", "");
+            assert_eq!(tiny.is_ok(), serde.is_ok(), "depth {}", depth);
+            if let Ok(out) = tiny {
+                assert_eq!(Ok(out), serde);
+            }
+        }
+    }
+}
@@ -0,0 +1,33 @@
+use crate::actions;
+
+mod bindings {
+    #![allow(clippy::too_many_arguments)]
+
+    wit_bindgen::generate!({
+        world: "action-module",
+        path: "wit",
+    });
+}
+
+struct ActionModule;
+
+impl bindings::Guest for ActionModule {
+    fn info() -> Result<String, String> {
+        actions::info()
+    }
+
+    fn get(action: String, params: String, network: String) -> Result<String, String> {
+        actions::get(&action, &params, &network)
+    }
+
+    fn post(
+        action: String,
+        params: String,
+        network: String,
+        hash_link_memo: String,
+    ) -> Result<String, String> {
+        actions::post(&action, &params, &network, &hash_link_memo)
+    }
+}
+
+bindings::export!(ActionModule with_types_in bindings);
@@ -0,0 +1,41 @@
+use wasm_bindgen::prelude::*;
+
+use crate::actions;
+
+#[wasm_bindgen]
+#[derive(Default)]
+pub struct WasmInterface;
+
+#[wasm_bindgen]
+impl WasmInterface {
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Self {
+        Self
+    }
+
+    #[wasm_bindgen(js_name = INFO)]
+    pub fn info(&self) -> Result<String, JsValue> {
+        actions::info().map_err(|e| JsValue::from_str(&e))
+    }
+
+    #[wasm_bindgen(js_name = POST)]
+    pub async fn post(
+        &self,
+        action: &str,
+        params: &str,
+        network: &str,
+        hash_link_memo: &str,
+    ) -> Result<String, JsValue> {
+        actions::post(action, params, network, hash_link_memo).map_err(|e| JsValue::from_str(&e))
+    }
+
+    #[wasm_bindgen(js_name = GET)]
+    pub async fn get(
+        &self,
+        action: &str,
+        params: &str,
+        network: &str,
+    ) -> Result<String, JsValue> {
+        actions::get(action, params, network).map_err(|e| JsValue::from_str(&e))
+    }
+}